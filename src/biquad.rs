@@ -0,0 +1,135 @@
+use std::f64::consts::PI;
+
+use crate::signal::Signal;
+
+// Second-order recursive (IIR) filter section. Coefficients are stored already
+// normalized by `a0` (so the implicit `a0` is 1.0) and applied with the Direct
+// Form II transposed difference equation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Biquad {
+    pub b0: f64,
+    pub b1: f64,
+    pub b2: f64,
+    pub a1: f64,
+    pub a2: f64,
+}
+
+impl Biquad {
+    pub fn new(b0: f64, b1: f64, b2: f64, a1: f64, a2: f64) -> Self {
+        Biquad { b0, b1, b2, a1, a2 }
+    }
+
+    // RBJ cookbook low-pass design via the bilinear transform.
+    pub fn low_pass(cutoff: f64, sample_rate: f64, q: f64) -> Self {
+        let (cos_w0, alpha) = Self::intermediates(cutoff, sample_rate, q);
+        let b0 = (1.0 - cos_w0) / 2.0;
+        let b1 = 1.0 - cos_w0;
+        let b2 = (1.0 - cos_w0) / 2.0;
+        let a0 = 1.0 + alpha;
+        let a1 = -2.0 * cos_w0;
+        let a2 = 1.0 - alpha;
+        Self::normalize(b0, b1, b2, a0, a1, a2)
+    }
+
+    // RBJ cookbook high-pass design via the bilinear transform.
+    pub fn high_pass(cutoff: f64, sample_rate: f64, q: f64) -> Self {
+        let (cos_w0, alpha) = Self::intermediates(cutoff, sample_rate, q);
+        let b0 = (1.0 + cos_w0) / 2.0;
+        let b1 = -(1.0 + cos_w0);
+        let b2 = (1.0 + cos_w0) / 2.0;
+        let a0 = 1.0 + alpha;
+        let a1 = -2.0 * cos_w0;
+        let a2 = 1.0 - alpha;
+        Self::normalize(b0, b1, b2, a0, a1, a2)
+    }
+
+    // RBJ cookbook peaking-EQ design boosting/cutting by `gain_db` at `cutoff`.
+    pub fn peaking(cutoff: f64, sample_rate: f64, q: f64, gain_db: f64) -> Self {
+        let (cos_w0, alpha) = Self::intermediates(cutoff, sample_rate, q);
+        let amp = 10.0_f64.powf(gain_db / 40.0);
+        let b0 = 1.0 + alpha * amp;
+        let b1 = -2.0 * cos_w0;
+        let b2 = 1.0 - alpha * amp;
+        let a0 = 1.0 + alpha / amp;
+        let a1 = -2.0 * cos_w0;
+        let a2 = 1.0 - alpha / amp;
+        Self::normalize(b0, b1, b2, a0, a1, a2)
+    }
+
+    fn intermediates(cutoff: f64, sample_rate: f64, q: f64) -> (f64, f64) {
+        let w0 = 2.0 * PI * cutoff / sample_rate;
+        let alpha = w0.sin() / (2.0 * q);
+        (w0.cos(), alpha)
+    }
+
+    fn normalize(b0: f64, b1: f64, b2: f64, a0: f64, a1: f64, a2: f64) -> Self {
+        Biquad {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+
+    // Apply the filter sample-by-sample with two state registers.
+    pub fn filter<S: Signal>(&self, input: &S) -> S {
+        let mut s1 = 0.0;
+        let mut s2 = 0.0;
+        let mut output = Vec::new();
+        for i in 0..input.len() {
+            let x = input[i];
+            let y = self.b0 * x + s1;
+            s1 = self.b1 * x - self.a1 * y + s2;
+            s2 = self.b2 * x - self.a2 * y;
+            output.push(y);
+        }
+        S::new(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RealDft;
+    use crate::signal::AperiodicSignal;
+
+    #[test]
+    fn low_pass_unity_dc_gain() {
+        let biquad = Biquad::low_pass(1000.0, 44100.0, 0.707);
+        let dc = AperiodicSignal::new(vec![1.0; 512]);
+        let filtered = biquad.filter(&dc);
+        // After the transient the output tracks the DC input with unity gain.
+        assert!((filtered[511usize] - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn high_pass_rejects_dc() {
+        let biquad = Biquad::high_pass(1000.0, 44100.0, 0.707);
+        let dc = AperiodicSignal::new(vec![1.0; 512]);
+        let filtered = biquad.filter(&dc);
+        assert!(filtered[511usize].abs() < 1e-6);
+    }
+
+    #[test]
+    fn peaking_boosts_matching_tone() {
+        let sample_rate = 64.0;
+        let length = 64;
+        let bin: usize = 8;
+        let freq = bin as f64 * sample_rate / length as f64;
+        let tone = AperiodicSignal::new(
+            (0..length)
+                .map(|n| (2.0 * PI * bin as f64 * n as f64 / length as f64).sin())
+                .collect(),
+        );
+
+        let biquad = Biquad::peaking(freq, sample_rate, 8.0, 12.0);
+        let filtered = biquad.filter(&tone);
+
+        let power = |s: &AperiodicSignal| {
+            let dft = RealDft::new(s.clone());
+            dft.cos_amplitude()[bin].powi(2) + dft.sin_amplitude()[bin].powi(2)
+        };
+        assert!(power(&filtered) > power(&tone));
+    }
+}
@@ -0,0 +1,134 @@
+use std::f64::consts::PI;
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    pub fn exp(theta: f64) -> Self {
+        Complex { re: theta.cos(), im: theta.sin() }
+    }
+}
+
+impl Add<Complex> for Complex {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Self::Output {
+        Complex { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+impl Sub<Complex> for Complex {
+    type Output = Complex;
+    fn sub(self, rhs: Complex) -> Self::Output {
+        Complex { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+}
+
+impl Mul<Complex> for Complex {
+    type Output = Complex;
+    fn mul(self, rhs: Complex) -> Self::Output {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+// In-place iterative radix-2 Cooley-Tukey FFT. `buffer.len()` must be a power
+// of two. With `inverse` set the twiddle sign is flipped and the result is
+// divided by N, yielding the inverse transform.
+pub fn transform(buffer: &mut [Complex], inverse: bool) {
+    let n = buffer.len();
+    if n <= 1 {
+        return;
+    }
+
+    // Bit-reversal reordering of the input indices.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            buffer.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut m = 2;
+    while m <= n {
+        let step = Complex::exp(sign * 2.0 * PI / m as f64);
+        let mut start = 0;
+        while start < n {
+            let mut twiddle = Complex::new(1.0, 0.0);
+            for k in 0..(m / 2) {
+                let even = buffer[start + k];
+                let odd = buffer[start + k + m / 2] * twiddle;
+                buffer[start + k] = even + odd;
+                buffer[start + k + m / 2] = even - odd;
+                twiddle = twiddle * step;
+            }
+            start += m;
+        }
+        m <<= 1;
+    }
+
+    if inverse {
+        let scale = 1.0 / n as f64;
+        for value in buffer.iter_mut() {
+            value.re *= scale;
+            value.im *= scale;
+        }
+    }
+}
+
+// Smallest power of two greater than or equal to `n`.
+pub fn next_power_of_two(n: usize) -> usize {
+    let mut size = 1;
+    while size < n {
+        size <<= 1;
+    }
+    size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn forward_inverse_round_trip() {
+        let original = vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(-3.0, 0.0),
+            Complex::new(4.0, 0.0),
+        ];
+        let mut buffer = original.clone();
+        transform(&mut buffer, false);
+        transform(&mut buffer, true);
+        for (got, want) in buffer.iter().zip(original.iter()) {
+            assert!(close(got.re, want.re) && close(got.im, want.im));
+        }
+    }
+
+    #[test]
+    fn next_power_of_two_rounds_up() {
+        assert_eq!(next_power_of_two(1), 1);
+        assert_eq!(next_power_of_two(5), 8);
+        assert_eq!(next_power_of_two(8), 8);
+    }
+}
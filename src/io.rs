@@ -0,0 +1,154 @@
+use std::fs;
+use std::io::{Error, ErrorKind, Result, Write};
+use std::path::Path;
+
+use crate::signal::{AperiodicSignal, Signal};
+
+// Quantization scale for 16-bit signed PCM output: [-1, 1] maps to
+// [-32767, 32767]. Reading 16-bit PCM normalizes by 32768 instead.
+const I16_SCALE: f64 = 32767.0;
+
+impl AperiodicSignal {
+    // Read a PCM WAV file, averaging channels to mono and normalizing integer
+    // samples to [-1, 1]. Returns the mono signal together with its sample rate.
+    pub fn from_wav<P: AsRef<Path>>(path: P) -> Result<(Self, u32)> {
+        let bytes = fs::read(path)?;
+        if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+            return Err(Error::new(ErrorKind::InvalidData, "not a RIFF/WAVE file"));
+        }
+
+        let mut channels = 0u16;
+        let mut sample_rate = 0u32;
+        let mut bits = 0u16;
+        let mut audio_format = 0u16;
+        let mut data: Option<&[u8]> = None;
+
+        let mut offset = 12;
+        while offset + 8 <= bytes.len() {
+            let id = &bytes[offset..offset + 4];
+            let size = read_u32(&bytes, offset + 4) as usize;
+            let body_start = offset + 8;
+            let body_end = (body_start + size).min(bytes.len());
+            match id {
+                b"fmt " => {
+                    audio_format = read_u16(&bytes, body_start);
+                    channels = read_u16(&bytes, body_start + 2);
+                    sample_rate = read_u32(&bytes, body_start + 4);
+                    bits = read_u16(&bytes, body_start + 14);
+                }
+                b"data" => data = Some(&bytes[body_start..body_end]),
+                _ => {}
+            }
+            // Chunks are padded to an even number of bytes.
+            offset = body_start + size + (size & 1);
+        }
+
+        let data = data.ok_or_else(|| Error::new(ErrorKind::InvalidData, "missing data chunk"))?;
+        if channels == 0 {
+            return Err(Error::new(ErrorKind::InvalidData, "missing fmt chunk"));
+        }
+
+        let samples = decode_samples(data, bits, audio_format)?;
+        let channels = channels as usize;
+        let mut mono = Vec::with_capacity(samples.len() / channels);
+        for frame in samples.chunks(channels) {
+            let sum: f64 = frame.iter().sum();
+            mono.push(sum / channels as f64);
+        }
+
+        Ok((AperiodicSignal::new(mono), sample_rate))
+    }
+
+    // Write the signal as a mono 16-bit PCM WAV file at the given sample rate.
+    pub fn write_wav<P: AsRef<Path>>(&self, path: P, sample_rate: u32) -> Result<()> {
+        let num_samples = self.len();
+        let data_len = num_samples * 2;
+        let mut out = Vec::with_capacity(44 + data_len);
+
+        out.extend_from_slice(b"RIFF");
+        out.extend_from_slice(&((36 + data_len) as u32).to_le_bytes());
+        out.extend_from_slice(b"WAVE");
+
+        out.extend_from_slice(b"fmt ");
+        out.extend_from_slice(&16u32.to_le_bytes());
+        out.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        out.extend_from_slice(&1u16.to_le_bytes()); // mono
+        out.extend_from_slice(&sample_rate.to_le_bytes());
+        out.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // byte rate
+        out.extend_from_slice(&2u16.to_le_bytes()); // block align
+        out.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+
+        out.extend_from_slice(b"data");
+        out.extend_from_slice(&(data_len as u32).to_le_bytes());
+        for i in 0..num_samples {
+            let clamped = self[i].clamp(-1.0, 1.0);
+            let quantized = (clamped * I16_SCALE).round() as i16;
+            out.extend_from_slice(&quantized.to_le_bytes());
+        }
+
+        let mut file = fs::File::create(path)?;
+        file.write_all(&out)
+    }
+}
+
+fn decode_samples(data: &[u8], bits: u16, audio_format: u16) -> Result<Vec<f64>> {
+    let samples = match (bits, audio_format) {
+        (8, _) => data.iter().map(|&b| (b as f64 - 128.0) / 128.0).collect(),
+        (16, _) => data
+            .chunks_exact(2)
+            .map(|c| i16::from_le_bytes([c[0], c[1]]) as f64 / 32768.0)
+            .collect(),
+        (24, _) => data
+            .chunks_exact(3)
+            .map(|c| {
+                let raw = i32::from_le_bytes([c[0], c[1], c[2], if c[2] & 0x80 != 0 { 0xff } else { 0 }]);
+                raw as f64 / 8_388_608.0
+            })
+            .collect(),
+        (32, 3) => data
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]) as f64)
+            .collect(),
+        (32, _) => data
+            .chunks_exact(4)
+            .map(|c| i32::from_le_bytes([c[0], c[1], c[2], c[3]]) as f64 / 2_147_483_648.0)
+            .collect(),
+        _ => return Err(Error::new(ErrorKind::InvalidData, "unsupported bit depth")),
+    };
+    Ok(samples)
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn wav_round_trip() {
+        let sample_rate = 8000;
+        let original = AperiodicSignal::new(
+            (0..256)
+                .map(|n| (2.0 * PI * 5.0 * n as f64 / 256.0).sin() * 0.5)
+                .collect(),
+        );
+        let path = env::temp_dir().join("signalprocessing_round_trip.wav");
+
+        original.write_wav(&path, sample_rate).unwrap();
+        let (read_back, read_rate) = AperiodicSignal::from_wav(&path).unwrap();
+
+        assert_eq!(read_rate, sample_rate);
+        assert_eq!(read_back.len(), original.len());
+        for i in 0..original.len() {
+            assert!((read_back[i] - original[i]).abs() < 1e-3);
+        }
+    }
+}
@@ -1,6 +1,12 @@
+mod biquad;
+mod fft;
+mod io;
 mod signal;
-use signal::Signal;
-use signal::AperiodicSignal;
+mod window;
+pub use biquad::Biquad;
+pub use signal::AperiodicSignal;
+pub use signal::Signal;
+pub use window::Window;
 
 use std::f64::consts::PI;
 
@@ -45,19 +51,57 @@ pub fn even_odd_decomposition<S: Signal>(signal: S) -> Vec<S> {
     vec![S::new(even), S::new(odd)]
 }
 
-struct RealDft {
+// Welch power spectral density estimate: average the periodograms of
+// overlapping, windowed segments to trade frequency resolution for reduced
+// variance. Segments of length `segment_len` advance by `segment_len - overlap`
+// and are each scaled by the window's power normalization before averaging.
+pub fn welch_psd<S: Signal>(signal: &S, segment_len: usize, overlap: usize, window: Window) -> AperiodicSignal {
+    assert!(overlap < segment_len, "overlap must be smaller than segment_len");
+    let step = segment_len - overlap;
+    let coefficients = window.values(segment_len);
+    let window_power: f64 = coefficients.iter().map(|w| w * w).sum();
+    let scale = 1.0 / window_power;
+    let bins = segment_len / 2 + 1;
+
+    let mut averaged = vec![0.0; bins];
+    let mut segments = 0;
+    let mut start = 0;
+    while start + segment_len <= signal.len() {
+        let windowed = AperiodicSignal::new(
+            (0..segment_len).map(|i| signal[start + i] * coefficients[i]).collect(),
+        );
+        let dft = RealDft::new(windowed);
+        for (k, value) in averaged.iter_mut().enumerate() {
+            let power = dft.cos_amplitude()[k].powi(2) + dft.sin_amplitude()[k].powi(2);
+            *value += power * scale;
+        }
+        segments += 1;
+        start += step;
+    }
+
+    if segments > 0 {
+        for value in averaged.iter_mut() {
+            *value /= segments as f64;
+        }
+    }
+    AperiodicSignal::new(averaged)
+}
+
+pub struct RealDft {
     cos_amplitude: AperiodicSignal,
     sin_amplitude: AperiodicSignal,
+    length: usize,
 }
 
 impl RealDft {
-    fn new<S:Signal>(signal: S) -> Self {
+    pub fn new<S:Signal>(signal: S) -> Self {
+        let length = signal.len();
         let mut cos_vec = Vec::new();
         let mut sin_vec = Vec::new();
         for k in 0..(signal.len() / 2 + 1) {
             let mut cos_sum = 0.0;
             let mut sin_sum = 0.0;
-            for n in 0..signal.len() + 1 {
+            for n in 0..signal.len() {
                 cos_sum += signal[n] * RealDft::base_cos(k, n, signal.len());
                 sin_sum += signal[n] * RealDft::base_sin(k, n, signal.len());
             }
@@ -66,7 +110,38 @@ impl RealDft {
         }
         let cos_amplitude = AperiodicSignal::new(cos_vec);
         let sin_amplitude = AperiodicSignal::new(sin_vec);
-        RealDft { cos_amplitude, sin_amplitude }
+        RealDft { cos_amplitude, sin_amplitude, length }
+    }
+
+    // Inverse transform: reconstruct the time-domain signal from the stored
+    // band amplitudes. The original length is carried from `new` so odd-length
+    // signals round-trip correctly. Interior bins are normalized by N/2; only
+    // the DC bin and (for even N) the true Nyquist cosine bin are normalized by
+    // N.
+    pub fn synthesize(&self) -> AperiodicSignal {
+        let bins = self.cos_amplitude.len();
+        let length = self.length;
+        let n_float = length as f64;
+
+        let mut cos_norm = Vec::new();
+        let mut sin_norm = Vec::new();
+        for k in 0..bins {
+            let is_dc_or_nyquist = k == 0 || 2 * k == length;
+            let cos_div = if is_dc_or_nyquist { n_float } else { n_float / 2.0 };
+            cos_norm.push(self.cos_amplitude[k] / cos_div);
+            sin_norm.push(self.sin_amplitude[k] / (n_float / 2.0));
+        }
+
+        let mut output = Vec::new();
+        for n in 0..length {
+            let mut sum = 0.0;
+            for k in 0..bins {
+                sum += cos_norm[k] * RealDft::base_cos(k, n, length);
+                sum += sin_norm[k] * RealDft::base_sin(k, n, length);
+            }
+            output.push(sum);
+        }
+        AperiodicSignal::new(output)
     }
 
     pub fn cos_amplitude(&self) -> &AperiodicSignal {
@@ -141,7 +216,47 @@ mod tests {
         let sig = AperiodicSignal::new(vec![4.0, 1.0, -5.0, -4.0]);
         let dft = RealDft::new(sig);
         assert_eq!(dft.cos_amplitude(), &AperiodicSignal::new(vec![-4.0, 9.0, 2.0]));
-        //assert_eq!(dft.sin_amplitude(), &AperiodicSignal::new(vec![0.0, 5.0, 0.0]));
+        let sin = dft.sin_amplitude();
+        let expected = [0.0, 5.0, 0.0];
+        for k in 0..expected.len() {
+            assert!((sin[k] - expected[k]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn welch_psd_peaks_at_tone() {
+        let length = 256;
+        let bin = 10;
+        let sig = AperiodicSignal::new(
+            (0..length)
+                .map(|n| (2.0 * PI * bin as f64 * n as f64 / 64.0).sin())
+                .collect(),
+        );
+        let psd = welch_psd(&sig, 64, 32, Window::Hann);
+        let peak = (0..psd.len())
+            .max_by(|a, b| psd[*a].partial_cmp(&psd[*b]).unwrap())
+            .unwrap();
+        assert_eq!(peak, bin);
+    }
+
+    #[test]
+    fn real_dft_synthesize_round_trip() {
+        let sig = AperiodicSignal::new(vec![4.0, 1.0, -5.0, -4.0, 2.0, 7.0, -1.0, 3.0]);
+        let reconstructed = RealDft::new(sig.clone()).synthesize();
+        assert_eq!(reconstructed.len(), sig.len());
+        for i in 0..sig.len() {
+            assert!((reconstructed[i] - sig[i]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn real_dft_synthesize_round_trip_odd_length() {
+        let sig = AperiodicSignal::new(vec![4.0, 1.0, -5.0, -4.0, 2.0, 7.0, -1.0]);
+        let reconstructed = RealDft::new(sig.clone()).synthesize();
+        assert_eq!(reconstructed.len(), sig.len());
+        for i in 0..sig.len() {
+            assert!((reconstructed[i] - sig[i]).abs() < 1e-9);
+        }
     }
 
 }
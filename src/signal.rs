@@ -1,9 +1,28 @@
 use std::ops::Index;
 
+use crate::fft::{self, Complex};
+
+// Below this combined output length the direct double loop is cheaper than
+// setting up three transforms, so `fold` keeps using it.
+const FFT_FOLD_THRESHOLD: usize = 64;
+
 pub trait Signal: Index<i32, Output=f64> + Index<usize, Output=f64> + Sized + Clone {
     fn new(values: Vec<f64>) -> Self;
     fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     fn fold(&self, rhs: &Self) -> Self {
+        let n = self.len();
+        let m = rhs.len();
+        if n + m - 1 < FFT_FOLD_THRESHOLD {
+            return self.fold_direct(rhs);
+        }
+        self.fold_fft(rhs)
+    }
+
+    // Naive O(N*M) convolution, kept as the short-input fallback.
+    fn fold_direct(&self, rhs: &Self) -> Self {
         let n = self.len();
         let m = rhs.len();
         let mut output = Vec::new();
@@ -16,6 +35,82 @@ pub trait Signal: Index<i32, Output=f64> + Index<usize, Output=f64> + Sized + Cl
         }
         Self::new(output)
     }
+
+    // O(N log N) convolution via zero-padded FFT, pointwise product and inverse.
+    fn fold_fft(&self, rhs: &Self) -> Self {
+        let n = self.len();
+        let m = rhs.len();
+        let out_len = n + m - 1;
+        let size = fft::next_power_of_two(out_len);
+
+        let mut left = vec![Complex::new(0.0, 0.0); size];
+        let mut right = vec![Complex::new(0.0, 0.0); size];
+        for i in 0..n {
+            left[i] = Complex::new(self[i], 0.0);
+        }
+        for j in 0..m {
+            right[j] = Complex::new(rhs[j], 0.0);
+        }
+
+        fft::transform(&mut left, false);
+        fft::transform(&mut right, false);
+        for i in 0..size {
+            left[i] = left[i] * right[i];
+        }
+        fft::transform(&mut left, true);
+
+        let output = left[..out_len].iter().map(|c| c.re).collect();
+        Self::new(output)
+    }
+
+    // Numerical derivative on a unit grid (see `derivative_spaced`).
+    fn derivative(&self) -> Self {
+        self.derivative_spaced(1.0)
+    }
+
+    // Second-order finite-difference derivative on a grid with spacing `h`,
+    // using a centered stencil in the interior and second-order one-sided
+    // closures at the endpoints so no access reaches past the signal bounds.
+    fn derivative_spaced(&self, h: f64) -> Self {
+        let n = self.len();
+        let mut output = vec![0.0; n];
+        if n == 1 {
+            return Self::new(output);
+        }
+        if n == 2 {
+            let slope = (self[1] - self[0]) / h;
+            output[0] = slope;
+            output[1] = slope;
+            return Self::new(output);
+        }
+        output[0] = (-3.0 * self[0] + 4.0 * self[1] - self[2]) / (2.0 * h);
+        for i in 1..n - 1 {
+            output[i] = (self[i + 1] - self[i - 1]) / (2.0 * h);
+        }
+        output[n - 1] = (3.0 * self[n - 1] - 4.0 * self[n - 2] + self[n - 3]) / (2.0 * h);
+        Self::new(output)
+    }
+
+    // Numerical integral on a unit grid (see `integrate_spaced`).
+    fn integrate(&self) -> Self {
+        self.integrate_spaced(1.0)
+    }
+
+    // Cumulative trapezoidal integration on a grid with spacing `h`, anchored at
+    // zero. The derivative stencils are exact for polynomials of degree at most
+    // two and the trapezoidal rule is exact for their (affine) derivatives, so
+    // the derivative/integral round-trip recovers such signals exactly (up to
+    // the integration constant); on higher-order inputs each is merely
+    // second-order accurate, so the round-trip carries the usual
+    // discretization error rather than recovering the input exactly.
+    fn integrate_spaced(&self, h: f64) -> Self {
+        let n = self.len();
+        let mut output = vec![0.0; n];
+        for i in 1..n {
+            output[i] = output[i - 1] + h * (self[i] + self[i - 1]) / 2.0;
+        }
+        Self::new(output)
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -108,4 +203,40 @@ mod tests {
         let kernel = AperiodicSignal::new(vec![2.0]);
         assert_eq!(signal.fold(&kernel), AperiodicSignal::new(vec![2.0, 4.0, -6.0, 8.0, 10.0]));
     }
+
+    #[test]
+    fn fold_fft_matches_direct() {
+        let signal = AperiodicSignal::new((0..50).map(|i| (i as f64 * 0.3).sin()).collect());
+        let kernel = AperiodicSignal::new((0..40).map(|i| 1.0 / (i as f64 + 1.0)).collect());
+        let fast = signal.fold_fft(&kernel);
+        let direct = signal.fold_direct(&kernel);
+        assert_eq!(fast.len(), direct.len());
+        for i in 0..direct.len() {
+            assert!((fast[i] - direct[i]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn derivative_of_linear_is_constant() {
+        let signal = AperiodicSignal::new((0..8).map(|i| 2.0 * i as f64 + 3.0).collect());
+        let derivative = signal.derivative();
+        for i in 0..derivative.len() {
+            assert!((derivative[i] - 2.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn integrate_of_derivative_recovers_affine_up_to_constant() {
+        // The derivative and trapezoidal integral only form an exact inverse
+        // pair for signals of degree at most two, so the recovery property is
+        // asserted on a ramp; a cubic or higher-order input would fail this by
+        // discretization error.
+        let signal = AperiodicSignal::new((0..8).map(|i| 2.0 * i as f64 + 3.0).collect());
+        let recovered = signal.derivative().integrate();
+        for i in 0..signal.len() {
+            let offset = recovered[i] - signal[i];
+            let first = recovered[0usize] - signal[0usize];
+            assert!((offset - first).abs() < 1e-9);
+        }
+    }
 }
@@ -0,0 +1,68 @@
+use std::f64::consts::PI;
+
+// Selects one of the supported window shapes for spectral estimation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Window {
+    Rectangular,
+    Hann,
+    Hamming,
+}
+
+impl Window {
+    // Sampled window coefficients of length `length`.
+    pub fn values(&self, length: usize) -> Vec<f64> {
+        match self {
+            Window::Rectangular => rectangular(length),
+            Window::Hann => hann(length),
+            Window::Hamming => hamming(length),
+        }
+    }
+}
+
+pub fn rectangular(length: usize) -> Vec<f64> {
+    vec![1.0; length]
+}
+
+pub fn hann(length: usize) -> Vec<f64> {
+    raised_cosine(length, 0.5, 0.5)
+}
+
+pub fn hamming(length: usize) -> Vec<f64> {
+    raised_cosine(length, 0.54, 0.46)
+}
+
+// Shared raised-cosine form `a0 - a1*cos(2*pi*n/(N-1))` used by Hann/Hamming.
+fn raised_cosine(length: usize, a0: f64, a1: f64) -> Vec<f64> {
+    if length <= 1 {
+        return vec![a0; length];
+    }
+    let denom = (length - 1) as f64;
+    (0..length)
+        .map(|n| a0 - a1 * (2.0 * PI * n as f64 / denom).cos())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rectangular_is_all_ones() {
+        assert_eq!(rectangular(4), vec![1.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn hann_endpoints_are_zero() {
+        let w = hann(8);
+        assert_eq!(w.len(), 8);
+        assert!(w[0].abs() < 1e-12);
+        assert!(w[7].abs() < 1e-12);
+    }
+
+    #[test]
+    fn hamming_endpoints_are_a0_minus_a1() {
+        let w = hamming(8);
+        assert!((w[0] - 0.08).abs() < 1e-12);
+        assert!((w[7] - 0.08).abs() < 1e-12);
+    }
+}